@@ -0,0 +1,25 @@
+use std::io::IsTerminal;
+
+/// Whether ANSI styling in rendered output is passed through or scrubbed,
+/// mirroring the common `--color=auto|always|never` convention. Shared by
+/// [`crate::display::expanded_display::ExpandedDisplay::force_color`] and
+/// [`crate::Table::stream_with_color`].
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum ColorMode {
+    /// Pass styling through only when stdout is an interactive terminal.
+    Auto,
+    /// Always pass styling through, even when not a terminal.
+    Always,
+    /// Always scrub styling, even when stdout is a terminal.
+    Never,
+}
+
+impl ColorMode {
+    pub(crate) fn resolve(self) -> bool {
+        match self {
+            ColorMode::Auto => std::io::stdout().is_terminal(),
+            ColorMode::Always => true,
+            ColorMode::Never => false,
+        }
+    }
+}
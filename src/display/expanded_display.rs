@@ -1,3 +1,9 @@
+use std::io::{self, Write};
+
+use terminal_size::{terminal_size, Width};
+use unicode_width::UnicodeWidthChar;
+
+use crate::display::color::ColorMode;
 use crate::Tabled;
 
 /// ExpandedDisplay display data in a 'expanded display mode' from postgress.
@@ -11,10 +17,49 @@ use crate::Tabled;
 pub struct ExpandedDisplay {
     format_record_splitter: fn(usize) -> String,
     format_value: fn(String) -> String,
+    wrap_width: Option<WrapWidth>,
+    ansi_aware: bool,
+    color_mode: ColorMode,
     fields: Vec<String>,
     records: Vec<Vec<String>>,
 }
 
+/// How [`ExpandedDisplay`] hard-wraps a value's lines, set via
+/// [`ExpandedDisplay::wrap_value_to`] or [`ExpandedDisplay::wrap_value_to_terminal`].
+#[derive(Debug, Clone, Copy)]
+enum WrapWidth {
+    /// Wrap at a fixed column count.
+    Fixed(usize),
+    /// Wrap at the live terminal width, detected on each render.
+    Terminal,
+}
+
+impl WrapWidth {
+    /// Resolves the wrap width for a value, given the field-name column
+    /// width it'll be printed alongside.
+    ///
+    /// `Terminal` wraps the whole *line*, not just the value, to the
+    /// terminal width - so the `max_field_width` spaces plus ` | ` prefix
+    /// every value line carries has to be subtracted first, or the rendered
+    /// line would still overflow by that much. `Fixed` is an explicit value
+    /// column width from the caller and is used as-is.
+    fn resolve(self, max_field_width: usize) -> usize {
+        match self {
+            WrapWidth::Fixed(width) => width,
+            WrapWidth::Terminal => {
+                let prefix_width = max_field_width + 3; // "<field> | "
+                terminal_width().saturating_sub(prefix_width).max(1)
+            }
+        }
+    }
+}
+
+fn terminal_width() -> usize {
+    terminal_size()
+        .map(|(Width(width), _)| width as usize)
+        .unwrap_or(80)
+}
+
 impl ExpandedDisplay {
     /// Creates a new instance of ExpandedDisplay
     pub fn new<T: Tabled>(iter: impl IntoIterator<Item = T>) -> Self {
@@ -26,6 +71,9 @@ impl ExpandedDisplay {
             fields: header,
             format_record_splitter: |i| format!("-[ RECORD {} ]-", i),
             format_value: |s| s,
+            wrap_width: None,
+            ansi_aware: false,
+            color_mode: ColorMode::Auto,
         }
     }
 
@@ -50,24 +98,140 @@ impl ExpandedDisplay {
         self.format_value = |s| s.escape_debug().to_string();
         self
     }
+
+    /// Hard-wraps each value line at `width` display columns, so long
+    /// single-line values don't overflow the terminal.
+    ///
+    /// Continuation lines are indented to line up under the value column,
+    /// i.e. `max_field_width` spaces followed by ` | `. Width is measured in
+    /// Unicode display width, not bytes or chars, so wide CJK glyphs count as
+    /// 2 and wrap at the right visual boundary.
+    pub fn wrap_value_to(&mut self, width: usize) -> &mut Self {
+        self.wrap_width = Some(WrapWidth::Fixed(width));
+        self
+    }
+
+    /// Like [`ExpandedDisplay::wrap_value_to`] but wraps to the live terminal
+    /// width, detected on each render; falls back to 80 when stdout isn't a
+    /// terminal or the width can't be detected. The field-name column prefix
+    /// (`max_field_width` spaces plus ` | `) is subtracted first, since it's
+    /// printed on the same line as the value, and the remaining width is
+    /// floored at 1 column for very narrow terminals.
+    pub fn wrap_value_to_terminal(&mut self) -> &mut Self {
+        self.wrap_width = Some(WrapWidth::Terminal);
+        self
+    }
+
+    /// Treats ANSI SGR escape sequences in a value as formatting rather than
+    /// text to escape, so embedded colors survive the expanded layout.
+    ///
+    /// When splitting a value into lines or wrapping it to a width, only the
+    /// printable (non-escape) display width is measured - the same
+    /// Unicode-width logic used for plain values, so colored and plain values
+    /// wrap identically - and a line break never lands inside an escape
+    /// sequence. The SGR state active at the break is re-emitted at the start
+    /// of the continuation line, and a reset is appended at the end of the
+    /// line it was carried from.
+    pub fn format_value_ansi_aware(&mut self) -> &mut Self {
+        self.ansi_aware = true;
+        self
+    }
+
+    /// Overrides automatic tty detection: `true` always passes ANSI styling
+    /// through, `false` always scrubs it, regardless of whether stdout is a
+    /// terminal.
+    ///
+    /// Without calling this, styling is scrubbed automatically whenever the
+    /// output isn't an interactive terminal (e.g. redirected to a file or
+    /// piped), so piping gives clean plaintext. Width is always measured on
+    /// the post-scrub text, so alignment matches what is actually written.
+    pub fn force_color(&mut self, force: bool) -> &mut Self {
+        self.color_mode = if force { ColorMode::Always } else { ColorMode::Never };
+        self
+    }
+
+    /// Writes records one at a time as they're pulled from `iter`, instead of
+    /// collecting them all upfront like [`ExpandedDisplay::new`] does.
+    ///
+    /// Each `-[ RECORD n ]-` splitter and its value lines are flushed right
+    /// after the item is consumed, so memory stays O(1) in the number of
+    /// records - useful for log tailing or a DB cursor. Since this reads
+    /// `self`, every other setting - [`ExpandedDisplay::format_value`],
+    /// [`ExpandedDisplay::wrap_value_to`]/[`ExpandedDisplay::wrap_value_to_terminal`],
+    /// [`ExpandedDisplay::format_value_ansi_aware`] and
+    /// [`ExpandedDisplay::force_color`] - applies exactly as it does for the
+    /// buffered [`Display`][std::fmt::Display] path; build the instance from
+    /// an empty iterator (headers don't depend on the records) to configure
+    /// it before streaming the real data through `iter`:
+    /// `ExpandedDisplay::new(std::iter::empty::<T>()).wrap_value_to_terminal().stream(writer, rows, None)`.
+    ///
+    /// The field-name column width comes from the headers, which are known
+    /// up front - unlike value widths, they don't depend on the records, so
+    /// no buffering is needed to compute it. Pass `field_width` to override
+    /// it outright instead, e.g. to line up with another stream's width.
+    pub fn stream<T, I>(
+        &self,
+        writer: &mut impl Write,
+        iter: I,
+        field_width: Option<usize>,
+    ) -> io::Result<()>
+    where
+        T: Tabled,
+        I: IntoIterator<Item = T>,
+    {
+        let fields = escape_fields(self.fields.clone());
+        let max_field_width = field_width
+            .unwrap_or_else(|| fields.iter().map(|f| f.chars().count()).max().unwrap_or_default());
+
+        let mut adapter = IoWriteAdapter::new(writer);
+        let result = self.write_records(
+            &mut adapter,
+            &fields,
+            max_field_width,
+            iter.into_iter().map(|item| item.fields()),
+        );
+
+        match (result, adapter.error) {
+            (Ok(()), None) => Ok(()),
+            (_, Some(error)) => Err(error),
+            (Err(_), None) => Err(io::Error::new(io::ErrorKind::Other, "formatting error")),
+        }
+    }
+
+    /// Writes every record's `-[ RECORD n ]-` splitter and value lines to
+    /// `sink`, applying this instance's formatting settings. Shared by
+    /// [`Display::fmt`][std::fmt::Display::fmt], which already has every
+    /// record in memory, and [`ExpandedDisplay::stream`], which pulls them
+    /// from its own `iter` one at a time.
+    fn write_records(
+        &self,
+        sink: &mut impl std::fmt::Write,
+        fields: &[String],
+        max_field_width: usize,
+        records: impl Iterator<Item = Vec<String>>,
+    ) -> std::fmt::Result {
+        let wrap_width = self.wrap_width.map(|w| w.resolve(max_field_width));
+        let use_color = self.color_mode.resolve();
+
+        for (i, record) in records.enumerate() {
+            assert_eq!(record.len(), fields.len());
+
+            writeln!(sink, "{}", (self.format_record_splitter)(i))?;
+            for (value, field) in record.iter().zip(fields.iter()) {
+                let value = (self.format_value)(value.clone());
+                let value = if use_color { value } else { strip_ansi(&value) };
+                let ansi_aware = self.ansi_aware && use_color;
+                write_record_line(sink, field, &value, max_field_width, wrap_width, ansi_aware)?;
+            }
+        }
+
+        Ok(())
+    }
 }
 
 impl std::fmt::Display for ExpandedDisplay {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        // It's possible that field|header can be a multiline string so
-        // we escape it and trim \" chars.
-        let fields = self
-            .fields
-            .iter()
-            .map(|f| {
-                let escaped = format!("{:?}", f);
-                escaped
-                    .chars()
-                    .skip(1)
-                    .take(escaped.len() - 1 - 1)
-                    .collect::<String>()
-            })
-            .collect::<Vec<_>>();
+        let fields = escape_fields(self.fields.clone());
 
         let max_field_width = fields
             .iter()
@@ -75,34 +239,432 @@ impl std::fmt::Display for ExpandedDisplay {
             .max()
             .unwrap_or_default();
 
-        for (i, record) in self.records.iter().enumerate() {
-            assert_eq!(record.len(), fields.len());
+        self.write_records(f, &fields, max_field_width, self.records.iter().cloned())
+    }
+}
 
-            writeln!(f, "{}", (self.format_record_splitter)(i))?;
-            for (value, field) in record.iter().zip(fields.iter()) {
-                let value = (self.format_value)(value.clone());
-                write_record_line(f, field, &value, max_field_width)?;
-            }
-        }
+/// Adapts an [`io::Write`] into [`std::fmt::Write`] so [`write_record_line`]
+/// and [`ExpandedDisplay::write_records`] can be shared between the buffered
+/// [`Display`][std::fmt::Display] path (writing to a [`std::fmt::Formatter`])
+/// and [`ExpandedDisplay::stream`] (writing to an [`io::Write`]). `fmt::Write`
+/// can't carry an [`io::Error`] through its `Result`, so the first one hit is
+/// stashed in `error` and surfaced by the caller afterwards. The writer is
+/// flushed after every complete line, so each record reaches it as soon as
+/// it's written.
+struct IoWriteAdapter<'a, W> {
+    writer: &'a mut W,
+    error: Option<io::Error>,
+}
 
-        Ok(())
+impl<'a, W: Write> IoWriteAdapter<'a, W> {
+    fn new(writer: &'a mut W) -> Self {
+        Self { writer, error: None }
     }
 }
 
+impl<'a, W: Write> std::fmt::Write for IoWriteAdapter<'a, W> {
+    fn write_str(&mut self, s: &str) -> std::fmt::Result {
+        let result = self.writer.write_all(s.as_bytes()).and_then(|()| {
+            if s.ends_with('\n') {
+                self.writer.flush()
+            } else {
+                Ok(())
+            }
+        });
+        result.map_err(|error| {
+            self.error = Some(error);
+            std::fmt::Error
+        })
+    }
+}
+
+// It's possible that field|header can be a multiline string so
+// we escape it and trim \" chars.
+fn escape_fields(fields: Vec<String>) -> Vec<String> {
+    fields
+        .iter()
+        .map(|f| {
+            let escaped = format!("{:?}", f);
+            escaped
+                .chars()
+                .skip(1)
+                .take(escaped.len() - 1 - 1)
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+}
+
 fn write_record_line(
-    f: &mut std::fmt::Formatter<'_>,
+    f: &mut impl std::fmt::Write,
     field: &str,
     value: &str,
     max_field_width: usize,
+    wrap_width: Option<usize>,
+    ansi_aware: bool,
 ) -> std::fmt::Result {
     if value.is_empty() {
         writeln!(f, "{:width$} | {}", field, value, width = max_field_width)?;
         return Ok(());
     }
 
-    for (i, line) in value.lines().enumerate() {
-        let field = if i == 0 { field } else { "" };
-        writeln!(f, "{:width$} | {}", field, line, width = max_field_width)?;
+    let mut field = field;
+    let mut active: Vec<String> = Vec::new();
+    let mut lines = value.lines().peekable();
+    while let Some(line) = lines.next() {
+        let mut wrapped_lines = if ansi_aware {
+            wrap_line_ansi_aware(line, wrap_width, &mut active)
+        } else {
+            wrap_line(line, wrap_width)
+        };
+
+        // The value's own '\n's are a break the source never reset color
+        // across, same as a width-driven break: close this physical line
+        // with a reset so the next one can re-open from a clean prefix.
+        if ansi_aware && lines.peek().is_some() && !active.is_empty() {
+            if let Some(last) = wrapped_lines.last_mut() {
+                last.push_str(ANSI_RESET);
+            }
+        }
+
+        for wrapped in wrapped_lines {
+            writeln!(f, "{:width$} | {}", field, wrapped, width = max_field_width)?;
+            field = "";
+        }
     }
     Ok(())
 }
+
+/// Splits `line` into sub-lines no wider than `width` display columns, or
+/// returns it whole when there's no wrap width configured.
+fn wrap_line(line: &str, width: Option<usize>) -> Vec<String> {
+    let width = match width {
+        Some(width) if width > 0 => width,
+        _ => return vec![line.to_string()],
+    };
+
+    let mut lines = Vec::new();
+    let mut current = String::new();
+    let mut current_width = 0;
+
+    for ch in line.chars() {
+        let ch_width = UnicodeWidthChar::width(ch).unwrap_or(0);
+        if current_width + ch_width > width && !current.is_empty() {
+            lines.push(std::mem::take(&mut current));
+            current_width = 0;
+        }
+
+        current.push(ch);
+        current_width += ch_width;
+    }
+
+    if !current.is_empty() || lines.is_empty() {
+        lines.push(current);
+    }
+
+    lines
+}
+
+#[cfg(test)]
+mod stream_tests {
+    use super::*;
+
+    struct Row {
+        name: &'static str,
+        bio: &'static str,
+    }
+
+    impl Tabled for Row {
+        fn fields(&self) -> Vec<String> {
+            vec![self.name.to_string(), self.bio.to_string()]
+        }
+
+        fn headers() -> Vec<String> {
+            vec![String::from("name"), String::from("bio")]
+        }
+    }
+
+    fn stream_to_string(display: &ExpandedDisplay, rows: Vec<Row>) -> String {
+        let mut buf = Vec::new();
+        display.stream(&mut buf, rows, None).unwrap();
+        String::from_utf8(buf).unwrap()
+    }
+
+    #[test]
+    fn stream_matches_the_buffered_display_for_the_same_settings() {
+        let rows = vec![Row { name: "a", bio: "one" }, Row { name: "b", bio: "two" }];
+
+        let mut configured = ExpandedDisplay::new(std::iter::empty::<Row>());
+        configured.force_color(false);
+        let streamed = stream_to_string(&configured, rows);
+
+        let mut buffered = ExpandedDisplay::new(vec![
+            Row { name: "a", bio: "one" },
+            Row { name: "b", bio: "two" },
+        ]);
+        buffered.force_color(false);
+
+        assert_eq!(streamed, buffered.to_string());
+    }
+
+    #[test]
+    fn stream_scrubs_ansi_when_color_is_forced_off() {
+        let mut display = ExpandedDisplay::new(std::iter::empty::<Row>());
+        display.force_color(false);
+
+        let rendered = stream_to_string(
+            &display,
+            vec![Row { name: "a", bio: "\x1b[31mred\x1b[0m" }],
+        );
+
+        assert!(!rendered.contains('\x1b'));
+        assert!(rendered.contains("red"));
+    }
+
+    #[test]
+    fn stream_passes_ansi_through_when_color_is_forced_on() {
+        let mut display = ExpandedDisplay::new(std::iter::empty::<Row>());
+        display.force_color(true);
+
+        let rendered = stream_to_string(
+            &display,
+            vec![Row { name: "a", bio: "\x1b[31mred\x1b[0m" }],
+        );
+
+        assert!(rendered.contains("\x1b[31mred\x1b[0m"));
+    }
+
+    #[test]
+    fn stream_wraps_long_values_like_the_buffered_display_does() {
+        let mut display = ExpandedDisplay::new(std::iter::empty::<Row>());
+        display.wrap_value_to(3);
+
+        let rendered = stream_to_string(&display, vec![Row { name: "a", bio: "abcdef" }]);
+
+        // max_field_width is 4 ("name"), so the value column starts right
+        // after "name | " - "abc" then "def" on its own continuation line.
+        assert_eq!(rendered, "-[ RECORD 0 ]-\nname | a\nbio  | abc\n     | def\n");
+    }
+}
+
+#[cfg(test)]
+mod wrap_tests {
+    use super::*;
+
+    #[test]
+    fn wrap_line_splits_at_unicode_width() {
+        // "日" is 2 columns wide, so this is 6 columns total, not 3 chars.
+        assert_eq!(wrap_line("日本語", Some(4)), vec!["日本", "語"]);
+    }
+
+    #[test]
+    fn wrap_line_passes_through_without_a_width() {
+        assert_eq!(wrap_line("a very long value", None), vec!["a very long value"]);
+    }
+
+    #[test]
+    fn terminal_wrap_width_subtracts_the_field_prefix() {
+        // Test harnesses run without a tty, so terminal_width() falls back
+        // to the documented default of 80. "<field> | " is max_field_width
+        // + 3 columns of prefix to subtract before the value gets what's
+        // left: 80 - (10 + 3) = 67.
+        assert_eq!(WrapWidth::Terminal.resolve(10), 67);
+    }
+
+    #[test]
+    fn fixed_wrap_width_ignores_the_field_prefix() {
+        // Unlike `Terminal`, `Fixed` is an explicit value-column width from
+        // the caller and is used as-is, prefix or no.
+        assert_eq!(WrapWidth::Fixed(10).resolve(5), 10);
+    }
+
+    #[test]
+    fn terminal_wrap_width_floors_at_one_column() {
+        // A terminal narrower than the prefix must not underflow to 0.
+        let width = WrapWidth::Terminal.resolve(usize::MAX / 2);
+        assert_eq!(width, 1);
+    }
+}
+
+const ANSI_RESET: &str = "\x1b[0m";
+
+pub(crate) enum AnsiToken {
+    /// A full `\x1b[...<letter>` SGR escape sequence.
+    Escape(String),
+    Char(char),
+}
+
+/// Splits a string into printable chars and whole ANSI escape sequences, so
+/// callers never have to reason about a sequence being cut in half.
+pub(crate) fn tokenize_ansi(s: &str) -> Vec<AnsiToken> {
+    let mut tokens = Vec::new();
+    let mut chars = s.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if c == '\x1b' && chars.peek() == Some(&'[') {
+            let mut seq = String::new();
+            seq.push(c);
+            seq.push(chars.next().unwrap());
+            for next in chars.by_ref() {
+                seq.push(next);
+                if next.is_ascii_alphabetic() {
+                    break;
+                }
+            }
+            tokens.push(AnsiToken::Escape(seq));
+        } else {
+            tokens.push(AnsiToken::Char(c));
+        }
+    }
+
+    tokens
+}
+
+/// Removes ANSI escape sequences, leaving only the printable text.
+pub(crate) fn strip_ansi(s: &str) -> String {
+    tokenize_ansi(s)
+        .into_iter()
+        .filter_map(|token| match token {
+            AnsiToken::Escape(_) => None,
+            AnsiToken::Char(c) => Some(c),
+        })
+        .collect()
+}
+
+/// Like [`wrap_line`], but treats ANSI SGR escape sequences as zero-width
+/// formatting instead of text: only the printable width is measured, a break
+/// never lands inside an escape sequence, and the active SGR state is
+/// re-emitted after each break so colors survive wrapping.
+///
+/// `active` carries the SGR state still open at the end of `line` in and out
+/// of the call: it's prepended to the first returned chunk and left holding
+/// whatever's still open when the call returns. A caller splitting a value
+/// on its own `\n`s (physical lines the source never reset color across) can
+/// pass the same `active` into each successive call to carry color across
+/// those breaks too, not just width-driven ones within a single call - it's
+/// the caller's job to append the closing reset to the last returned chunk
+/// when another physical line follows, the same way this function does for
+/// an internal width-driven break.
+fn wrap_line_ansi_aware(line: &str, width: Option<usize>, active: &mut Vec<String>) -> Vec<String> {
+    let width = width.filter(|&width| width > 0);
+
+    let mut lines = Vec::new();
+    let mut current = String::new();
+    for code in active.iter() {
+        current.push_str(code);
+    }
+    let mut current_width = 0;
+
+    for token in tokenize_ansi(line) {
+        match token {
+            AnsiToken::Escape(seq) => {
+                if seq == ANSI_RESET || seq == "\x1b[m" {
+                    active.clear();
+                } else {
+                    active.push(seq.clone());
+                }
+                current.push_str(&seq);
+            }
+            AnsiToken::Char(c) => {
+                let ch_width = UnicodeWidthChar::width(c).unwrap_or(0);
+                if let Some(width) = width {
+                    if current_width + ch_width > width && !current.is_empty() {
+                        if !active.is_empty() {
+                            current.push_str(ANSI_RESET);
+                        }
+                        lines.push(std::mem::take(&mut current));
+                        current_width = 0;
+                        for code in active.iter() {
+                            current.push_str(code);
+                        }
+                    }
+                }
+
+                current.push(c);
+                current_width += ch_width;
+            }
+        }
+    }
+
+    if !current.is_empty() || lines.is_empty() {
+        lines.push(current);
+    }
+
+    lines
+}
+
+#[cfg(test)]
+mod ansi_wrap_tests {
+    use super::*;
+
+    #[test]
+    fn measures_only_printable_width_and_leaves_short_escapes_untouched() {
+        let mut active = Vec::new();
+        assert_eq!(
+            wrap_line_ansi_aware("\x1b[31mred\x1b[0m", Some(10), &mut active),
+            vec!["\x1b[31mred\x1b[0m"]
+        );
+        assert!(active.is_empty());
+    }
+
+    #[test]
+    fn never_breaks_inside_an_escape_sequence_and_carries_state_across_a_width_break() {
+        // "redred" is 6 printable columns; wrapping at 3 must split between
+        // the two words, keeping the leading escape sequence whole, and must re-open
+        // red on the continuation line since the source never reset first.
+        let mut active = Vec::new();
+        assert_eq!(
+            wrap_line_ansi_aware("\x1b[31mredred", Some(3), &mut active),
+            vec!["\x1b[31mred\x1b[0m", "\x1b[31mred"]
+        );
+        assert_eq!(active, vec!["\x1b[31m".to_string()]);
+    }
+
+    #[test]
+    fn carries_active_state_across_separate_calls_for_a_values_own_newlines() {
+        // Simulates write_record_line's loop over value.lines(): two physical
+        // lines from one value, joined only by a literal '\n' the source
+        // never reset color across. Each runs through its own call sharing
+        // `active`; closing the first one out with a reset before moving on
+        // is the caller's job (write_record_line does it when another
+        // physical line follows), not this function's.
+        let mut active = Vec::new();
+        let mut first = wrap_line_ansi_aware("\x1b[31mred", None, &mut active);
+        assert_eq!(first, vec!["\x1b[31mred"]);
+        assert_eq!(active, vec!["\x1b[31m".to_string()]);
+        first.last_mut().unwrap().push_str(ANSI_RESET);
+        assert_eq!(first, vec!["\x1b[31mred\x1b[0m"]);
+
+        let second = wrap_line_ansi_aware("still red", None, &mut active);
+        assert_eq!(second, vec!["\x1b[31mstill red"]);
+    }
+
+    #[test]
+    fn an_explicit_reset_clears_carried_state() {
+        let mut active = Vec::new();
+        wrap_line_ansi_aware("\x1b[31mred\x1b[0m", None, &mut active);
+        assert!(active.is_empty());
+
+        let second = wrap_line_ansi_aware("plain", None, &mut active);
+        assert_eq!(second, vec!["plain"]);
+    }
+
+    struct OneLine<'a>(&'a str);
+
+    impl std::fmt::Display for OneLine<'_> {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write_record_line(f, "field", self.0, 5, None, true)
+        }
+    }
+
+    #[test]
+    fn write_record_line_carries_color_across_the_values_own_newline() {
+        // The regression this module exists to fix: a value with a literal
+        // '\n' and no explicit reset before it must still show color on
+        // both physical lines, not just the first.
+        let rendered = OneLine("\x1b[31mred\nstill red").to_string();
+        assert_eq!(
+            rendered,
+            "field | \x1b[31mred\x1b[0m\n      | \x1b[31mstill red\n"
+        );
+    }
+}
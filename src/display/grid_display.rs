@@ -0,0 +1,228 @@
+use unicode_width::UnicodeWidthStr;
+
+/// Order in which cells are assigned to a [`GridDisplay`]'s columns.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    /// Fill a row at a time, left to right, top to bottom - like `ls -x`.
+    AcrossFirst,
+    /// Fill a column at a time, top to bottom, left to right - like `ls -C`.
+    DownFirst,
+}
+
+/// Lays out short, single-field records into as many columns as fit the
+/// console width, instead of one row per record - the column-packing idea
+/// behind `exa`'s grid output.
+#[derive(Debug)]
+pub struct GridDisplay {
+    cells: Vec<String>,
+    console_width: usize,
+    separator_width: usize,
+    direction: Direction,
+}
+
+impl GridDisplay {
+    /// Creates a new instance, fitting `cells` into `console_width` columns.
+    pub fn new(cells: Vec<String>, console_width: usize) -> Self {
+        Self {
+            cells,
+            console_width,
+            separator_width: 2,
+            direction: Direction::AcrossFirst,
+        }
+    }
+
+    /// Sets the number of spaces printed between columns. Default is 2.
+    pub fn separator_width(&mut self, width: usize) -> &mut Self {
+        self.separator_width = width;
+        self
+    }
+
+    /// Sets the order cells are assigned to columns in. Default is
+    /// [`Direction::AcrossFirst`].
+    pub fn direction(&mut self, direction: Direction) -> &mut Self {
+        self.direction = direction;
+        self
+    }
+
+    /// Searches upward from 1 line for the smallest number of lines such that
+    /// the resulting `ceil(len / lines)` columns - each sized to the max
+    /// display width of the cells assigned to it, plus a separator - sum to
+    /// no more than the console width.
+    ///
+    /// Returns the chosen `(columns, lines, column_widths)`, or `None` if not
+    /// even a single column fits. `lines` is returned alongside `columns` -
+    /// rather than left for the caller to recompute as `ceil(len / columns)`
+    /// - because a different `lines` candidate can map to the same `columns`
+    /// count while partitioning cells (under `Direction::DownFirst`)
+    /// differently; reusing the exact `lines` the widths were measured
+    /// against keeps the partitioning consistent with `column_widths`.
+    fn fit(&self) -> Option<(usize, usize, Vec<usize>)> {
+        let len = self.cells.len();
+        if len == 0 {
+            return Some((0, 0, Vec::new()));
+        }
+
+        let widths: Vec<usize> = self
+            .cells
+            .iter()
+            .map(|cell| UnicodeWidthStr::width(cell.as_str()))
+            .collect();
+
+        for lines in 1..=len {
+            let columns = (len + lines - 1) / lines;
+
+            // Even with zero-width cells the separators alone don't fit;
+            // don't bother measuring cells for this candidate.
+            if columns * self.separator_width > self.console_width {
+                continue;
+            }
+
+            let mut column_widths = vec![0; columns];
+            for (i, &width) in widths.iter().enumerate() {
+                let column = match self.direction {
+                    Direction::AcrossFirst => i % columns,
+                    Direction::DownFirst => i / lines,
+                };
+                column_widths[column] = column_widths[column].max(width);
+            }
+
+            let total_width: usize = column_widths
+                .iter()
+                .map(|width| width + self.separator_width)
+                .sum();
+
+            if total_width <= self.console_width {
+                return Some((columns, lines, column_widths));
+            }
+        }
+
+        None
+    }
+}
+
+impl std::fmt::Display for GridDisplay {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let len = self.cells.len();
+        if len == 0 {
+            return Ok(());
+        }
+
+        // Fall back to a single, unconstrained column when nothing fits the
+        // console width, so data is never silently dropped.
+        let (columns, lines, column_widths) = self.fit().unwrap_or_else(|| {
+            let width = self
+                .cells
+                .iter()
+                .map(|cell| UnicodeWidthStr::width(cell.as_str()))
+                .max()
+                .unwrap_or(0);
+            (1, len, vec![width])
+        });
+
+        let separator = " ".repeat(self.separator_width);
+
+        for row in 0..lines {
+            let mut printed = false;
+            for column in 0..columns {
+                let index = match self.direction {
+                    Direction::AcrossFirst => row * columns + column,
+                    Direction::DownFirst => column * lines + row,
+                };
+
+                let cell = match self.cells.get(index) {
+                    Some(cell) => cell,
+                    None => continue,
+                };
+
+                if printed {
+                    write!(f, "{}", separator)?;
+                }
+
+                let width = column_widths[column];
+                let padding = width.saturating_sub(UnicodeWidthStr::width(cell.as_str()));
+                write!(f, "{}{}", cell, " ".repeat(padding))?;
+                printed = true;
+            }
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cells(values: &[&str]) -> Vec<String> {
+        values.iter().map(|v| v.to_string()).collect()
+    }
+
+    #[test]
+    fn fits_as_many_columns_as_the_width_allows() {
+        // 4 cells of width 1, separator 2: "a  b  c  d" is 10 columns, fits in 12.
+        let grid = GridDisplay::new(cells(&["a", "b", "c", "d"]), 12);
+        assert_eq!(grid.fit(), Some((4, 1, vec![1, 1, 1, 1])));
+    }
+
+    #[test]
+    fn falls_back_to_fewer_columns_when_the_width_is_tight() {
+        // Same 4 cells, but only enough room for 2 columns (2*(1+2) = 6 <= 7).
+        let grid = GridDisplay::new(cells(&["a", "b", "c", "d"]), 7);
+        assert_eq!(grid.fit(), Some((2, 2, vec![1, 1])));
+    }
+
+    #[test]
+    fn early_aborts_when_even_separators_overflow() {
+        let grid = GridDisplay::new(cells(&["a", "b", "c"]), 1);
+        assert_eq!(grid.fit(), None);
+    }
+
+    #[test]
+    fn fmt_reuses_fits_lines_instead_of_recomputing_from_columns() {
+        // Regression: with cells ["a", "b", "c", "D"*30, ""] and width 34,
+        // `fit()` settles on columns=2 via lines=4 (DownFirst groups
+        // [a b c D*30] / [""], widths [30, 0]). A naive `fmt()` that instead
+        // recomputes `lines = ceil(5 / 2) = 3` would re-partition as
+        // [a b c] / [D*30 ""], putting the 30-wide cell in the column
+        // declared width 0, blowing past the console width.
+        let mut grid = GridDisplay::new(
+            cells(&["a", "b", "c", &"D".repeat(30), ""]),
+            34,
+        );
+        grid.direction(Direction::DownFirst);
+        let rendered = grid.to_string();
+        for line in rendered.lines() {
+            assert!(
+                UnicodeWidthStr::width(line) <= 34,
+                "line {:?} exceeds the 34-column budget",
+                line
+            );
+        }
+    }
+
+    #[test]
+    fn down_first_fills_columns_before_rows() {
+        // 4 cells over 2 lines: across-first is [a b] [c d] per row; down-first
+        // is [a c] [b d] per row, i.e. column 0 gets the first 2 cells in order.
+        let mut grid = GridDisplay::new(cells(&["a", "b", "c", "d"]), 7);
+        grid.direction(Direction::DownFirst);
+        assert_eq!(grid.to_string(), "a  c\nb  d\n");
+    }
+
+    #[test]
+    fn across_first_fills_rows_before_columns() {
+        let mut grid = GridDisplay::new(cells(&["a", "b", "c", "d"]), 7);
+        grid.direction(Direction::AcrossFirst);
+        assert_eq!(grid.to_string(), "a  b\nc  d\n");
+    }
+
+    #[test]
+    fn widths_count_unicode_display_width_not_chars() {
+        // "日" is 2 columns wide despite being 1 char, so its column has to
+        // be sized 2, not 1: "a" below it is padded to 2 to keep "y" aligned
+        // under "x".
+        let grid = GridDisplay::new(cells(&["日", "x", "a", "y"]), 7);
+        assert_eq!(grid.to_string(), "日  x\na   y\n");
+    }
+}
@@ -0,0 +1,175 @@
+use std::io;
+
+use unicode_width::UnicodeWidthStr;
+
+use crate::display::expanded_display::ExpandedDisplay;
+use crate::{Table, Tabled};
+
+impl Table {
+    /// Renders `iter` as a normal data table, alongside a companion
+    /// descriptor block listing each field's name and inferred shape -
+    /// column index, whether its values are multiline, and their max width.
+    ///
+    /// Useful for diagnosing why columns render unexpectedly: call
+    /// [`Inspect::write_description`] to send the descriptor to a separate
+    /// writer (e.g. stderr) so it can be injected mid-pipeline without
+    /// corrupting the primary table on stdout.
+    pub fn inspect<T: Tabled>(iter: impl IntoIterator<Item = T>) -> Inspect<T> {
+        Inspect::new(iter)
+    }
+}
+
+/// A data set paired with a descriptor of its fields' shape, produced by
+/// [`Table::inspect`].
+pub struct Inspect<T> {
+    data: Vec<T>,
+}
+
+/// One row of the descriptor block: a field's name next to its inferred
+/// shape.
+struct FieldShape {
+    name: String,
+    column: usize,
+    multiline: bool,
+    max_width: usize,
+}
+
+impl Tabled for FieldShape {
+    fn fields(&self) -> Vec<String> {
+        vec![
+            self.name.clone(),
+            self.column.to_string(),
+            self.multiline.to_string(),
+            self.max_width.to_string(),
+        ]
+    }
+
+    fn headers() -> Vec<String> {
+        vec![
+            String::from("field"),
+            String::from("column"),
+            String::from("multiline"),
+            String::from("max_width"),
+        ]
+    }
+}
+
+impl<T: Tabled> Inspect<T> {
+    fn new(iter: impl IntoIterator<Item = T>) -> Self {
+        Self {
+            data: iter.into_iter().collect(),
+        }
+    }
+
+    fn shapes(&self) -> Vec<FieldShape> {
+        let headers = T::headers();
+        let mut max_widths = vec![0; headers.len()];
+        let mut multiline = vec![false; headers.len()];
+
+        for item in &self.data {
+            for (i, value) in item.fields().iter().enumerate() {
+                max_widths[i] = max_widths[i].max(UnicodeWidthStr::width(value.as_str()));
+                multiline[i] = multiline[i] || value.contains('\n');
+            }
+        }
+
+        headers
+            .into_iter()
+            .enumerate()
+            .map(|(column, name)| FieldShape {
+                name,
+                column,
+                multiline: multiline[column],
+                max_width: max_widths[column],
+            })
+            .collect()
+    }
+
+    /// Writes just the descriptor block - reusing [`ExpandedDisplay`]'s
+    /// key/value formatting - without the data table, so it can be routed to
+    /// a writer other than the one the data table is printed to.
+    pub fn write_description(&self, writer: &mut impl io::Write) -> io::Result<()> {
+        write!(writer, "{}", ExpandedDisplay::new(self.shapes()))
+    }
+}
+
+impl<T: Tabled> std::fmt::Display for Inspect<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", ExpandedDisplay::new(self.shapes()))?;
+        write!(f, "{}", Table::new(&self.data))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Row {
+        name: &'static str,
+        bio: &'static str,
+    }
+
+    impl Tabled for Row {
+        fn fields(&self) -> Vec<String> {
+            vec![self.name.to_string(), self.bio.to_string()]
+        }
+
+        fn headers() -> Vec<String> {
+            vec![String::from("name"), String::from("bio")]
+        }
+    }
+
+    #[test]
+    fn shapes_one_row_per_header_in_order() {
+        let shapes = Inspect::new(Vec::<Row>::new()).shapes();
+        let names: Vec<&str> = shapes.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, vec!["name", "bio"]);
+
+        let columns: Vec<usize> = shapes.iter().map(|s| s.column).collect();
+        assert_eq!(columns, vec![0, 1]);
+    }
+
+    #[test]
+    fn shapes_tracks_max_width_in_unicode_display_columns() {
+        let rows = vec![
+            Row { name: "a", bio: "x" },
+            Row { name: "日", bio: "" },
+        ];
+        let shapes = Inspect::new(rows).shapes();
+
+        // "日" is 2 display columns despite being 1 char, so it beats "a"
+        // (1) and the max is 2 - measuring chars instead of display width
+        // would give 1.
+        assert_eq!(shapes[0].max_width, 2);
+        assert_eq!(shapes[1].max_width, 1);
+    }
+
+    #[test]
+    fn shapes_flags_a_field_as_multiline_if_any_value_contains_a_newline() {
+        let rows = vec![
+            Row { name: "a", bio: "one\ntwo" },
+            Row { name: "b", bio: "single" },
+        ];
+        let shapes = Inspect::new(rows).shapes();
+
+        assert!(!shapes[0].multiline);
+        assert!(shapes[1].multiline);
+    }
+
+    #[test]
+    fn write_description_matches_the_descriptor_half_of_display_and_omits_the_data_table() {
+        let rows = vec![Row { name: "a", bio: "one" }, Row { name: "b", bio: "two" }];
+        let inspect = Inspect::new(rows);
+
+        let mut buf = Vec::new();
+        inspect.write_description(&mut buf).unwrap();
+        let description = String::from_utf8(buf).unwrap();
+
+        let expected = ExpandedDisplay::new(inspect.shapes()).to_string();
+        assert_eq!(description, expected);
+
+        let full = inspect.to_string();
+        assert!(full.starts_with(&description));
+        assert_ne!(full, description, "Display::fmt should also print the data table");
+    }
+}
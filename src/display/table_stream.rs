@@ -0,0 +1,168 @@
+use std::io::{self, Write};
+
+use crate::display::color::ColorMode;
+use crate::display::expanded_display::strip_ansi;
+use crate::{Table, Tabled};
+
+impl Table {
+    /// Writes a table row by row as records are pulled from `iter`, instead of
+    /// collecting them all upfront like [`Table::new`] does.
+    ///
+    /// This is the `Table` counterpart of [`ExpandedDisplay::stream`] and
+    /// exists for the same reason: large or unbounded sources (log tailing, a
+    /// DB cursor) shouldn't need to live in memory all at once.
+    ///
+    /// Because column widths depend on every cell in a column - unlike
+    /// `ExpandedDisplay`'s field-name column, which is sized from the header
+    /// row alone - there's no way to derive them without seeing every
+    /// record. The caller has to fix the column widths up front.
+    ///
+    /// ANSI styling in cells is scrubbed automatically whenever stdout isn't
+    /// an interactive terminal, same as
+    /// [`ExpandedDisplay::force_color`][force_color]; use
+    /// [`Table::stream_with_color`] to override that.
+    ///
+    /// [`ExpandedDisplay::stream`]: crate::display::expanded_display::ExpandedDisplay::stream
+    /// [force_color]: crate::display::expanded_display::ExpandedDisplay::force_color
+    pub fn stream<T, I>(writer: &mut impl Write, iter: I, widths: &[usize]) -> io::Result<()>
+    where
+        T: Tabled,
+        I: IntoIterator<Item = T>,
+    {
+        Self::stream_with_color(writer, iter, widths, None)
+    }
+
+    /// Like [`Table::stream`], but overrides the automatic tty detection:
+    /// `Some(true)` always passes ANSI styling through, `Some(false)` always
+    /// scrubs it, and `None` keeps the automatic tty-based behavior.
+    ///
+    /// Scrubbing runs on each cell before it's measured and padded to its
+    /// column width, so the fixed-width alignment the caller asked for
+    /// matches what's actually written - the `Table` counterpart of what
+    /// [`ExpandedDisplay::force_color`] does for the expanded layout.
+    ///
+    /// [`ExpandedDisplay::force_color`]: crate::display::expanded_display::ExpandedDisplay::force_color
+    pub fn stream_with_color<T, I>(
+        writer: &mut impl Write,
+        iter: I,
+        widths: &[usize],
+        force_color: Option<bool>,
+    ) -> io::Result<()>
+    where
+        T: Tabled,
+        I: IntoIterator<Item = T>,
+    {
+        let use_color = match force_color {
+            None => ColorMode::Auto,
+            Some(true) => ColorMode::Always,
+            Some(false) => ColorMode::Never,
+        }
+        .resolve();
+
+        let headers = T::headers();
+        write_row(writer, &headers, widths, use_color)?;
+
+        for item in iter {
+            write_row(writer, &item.fields(), widths, use_color)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Writes one pipe-table row, wrapping any cell containing `\n` onto
+/// additional physical lines instead of corrupting the row - the same
+/// problem [`ExpandedDisplay::stream`] solves for the expanded layout.
+/// Cells that don't reach the tallest one in the row are padded with blank
+/// lines so every column lines up. When `use_color` is `false`, ANSI escape
+/// sequences are scrubbed from each cell before it's split and measured, so
+/// alignment reflects what's actually written.
+///
+/// [`ExpandedDisplay::stream`]: crate::display::expanded_display::ExpandedDisplay::stream
+fn write_row(
+    writer: &mut impl Write,
+    cells: &[String],
+    widths: &[usize],
+    use_color: bool,
+) -> io::Result<()> {
+    assert_eq!(cells.len(), widths.len());
+
+    let scrubbed;
+    let cells: &[String] = if use_color {
+        cells
+    } else {
+        scrubbed = cells.iter().map(|cell| strip_ansi(cell)).collect::<Vec<_>>();
+        &scrubbed
+    };
+
+    let lines: Vec<Vec<&str>> = cells.iter().map(|cell| cell.lines().collect()).collect();
+    let height = lines.iter().map(|cell| cell.len()).max().unwrap_or(1).max(1);
+
+    for row in 0..height {
+        write!(writer, "|")?;
+        for (cell_lines, width) in lines.iter().zip(widths.iter()) {
+            let line = cell_lines.get(row).copied().unwrap_or("");
+            write!(writer, " {:width$} |", line, width = width)?;
+        }
+        writeln!(writer)?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn render(cells: &[&str], widths: &[usize]) -> String {
+        render_with_color(cells, widths, true)
+    }
+
+    fn render_with_color(cells: &[&str], widths: &[usize], use_color: bool) -> String {
+        let cells: Vec<String> = cells.iter().map(|c| c.to_string()).collect();
+        let mut buf = Vec::new();
+        write_row(&mut buf, &cells, widths, use_color).unwrap();
+        String::from_utf8(buf).unwrap()
+    }
+
+    #[test]
+    fn writes_a_single_line_row() {
+        assert_eq!(render(&["a", "bb"], &[1, 2]), "| a | bb |\n");
+    }
+
+    #[test]
+    fn wraps_a_multiline_cell_onto_additional_physical_lines() {
+        // "x\ny" spans two lines, so the whole row grows to two physical
+        // lines instead of corrupting the pipe table on one.
+        assert_eq!(
+            render(&["x\ny", "z"], &[1, 1]),
+            "| x | z |\n| y |   |\n"
+        );
+    }
+
+    #[test]
+    fn pads_shorter_cells_with_blank_lines_to_match_the_tallest() {
+        assert_eq!(
+            render(&["a\nb\nc", "z"], &[1, 1]),
+            "| a | z |\n| b |   |\n| c |   |\n"
+        );
+    }
+
+    #[test]
+    fn passes_ansi_styling_through_when_color_is_on() {
+        assert_eq!(
+            render_with_color(&["\x1b[31mred\x1b[0m"], &[3], true),
+            "| \x1b[31mred\x1b[0m |\n"
+        );
+    }
+
+    #[test]
+    fn scrubs_ansi_styling_and_measures_the_post_scrub_text_when_color_is_off() {
+        // The escaped cell is 12 bytes but only 3 columns wide once scrubbed;
+        // padding must be computed against the scrubbed text, not the raw one.
+        assert_eq!(
+            render_with_color(&["\x1b[31mred\x1b[0m"], &[5], false),
+            "| red   |\n"
+        );
+    }
+}